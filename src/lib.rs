@@ -1,10 +1,26 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::fs;
-use std::time::{SystemTime, UNIX_EPOCH, Duration};
+use std::time::{SystemTime, UNIX_EPOCH, Duration, Instant};
 use std::thread;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::sync::OnceLock;
+
+/// Sparkline levels used to render bounded history as a single line of block glyphs.
+const SPARKLINE_LEVELS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Maps a `[0, 100]` percentage to one of `SPARKLINE_LEVELS`.
+fn sparkline_char(value: f32) -> char {
+    let idx = ((value / 100.0) * 8.0).round().clamp(0.0, 8.0) as usize;
+    SPARKLINE_LEVELS[idx]
+}
+
+/// Renders a bounded sample history as a single-line Unicode block sparkline.
+fn render_sparkline(history: &VecDeque<f32>) -> String {
+    history.iter().map(|&v| sparkline_char(v)).collect()
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemStats {
@@ -12,6 +28,45 @@ pub struct SystemStats {
     pub cpu: CpuStats,
     pub memory: MemoryStats,
     pub system_info: BasicSystemInfo,
+    pub processes: Vec<ProcessStats>,
+    pub network: Vec<NetworkStats>,
+    pub disks: Vec<DiskStats>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkStats {
+    pub interface: String,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_bytes_per_sec: f64,
+    pub tx_bytes_per_sec: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiskStats {
+    pub mount_point: String,
+    pub total_bytes: u64,
+    pub available_bytes: u64,
+    pub read_bytes: u64,
+    pub write_bytes: u64,
+    pub read_bytes_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProcessStats {
+    pub pid: u32,
+    pub name: String,
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+    pub disk_read_bytes: u64,
+    pub disk_write_bytes: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortBy {
+    Cpu,
+    Memory,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +75,15 @@ pub struct CpuStats {
     pub cores: usize,
     pub temperature: f32,
     pub load_avg: Option<[f32; 3]>,
+    pub per_core: Vec<f32>,
+    pub sensors: Vec<TempSensor>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TempSensor {
+    pub label: String,
+    pub temp_c: f32,
+    pub critical: Option<f32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -47,6 +111,24 @@ pub struct MonitorConfig {
     pub temperature_threshold: f32,
     pub enable_alerts: bool,
     pub log_file: String,
+    pub cpu_history_size: usize,
+    pub top_processes: usize,
+    pub sort_by: SortBy,
+    pub network_threshold: f64,
+    pub disk_threshold: f64,
+    pub output_mode: OutputMode,
+}
+
+/// Controls how `SystemMonitorRunner` renders each iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutputMode {
+    /// The current verbose multi-line block, plus the usual file logging.
+    Full,
+    /// A single condensed line: `cpu% mem% temp load`.
+    Basic,
+    /// One JSON object per iteration on stdout, for piping into other tools.
+    /// File logging is skipped since stdout already carries the full record.
+    Json,
 }
 
 impl Default for MonitorConfig {
@@ -59,6 +141,12 @@ impl Default for MonitorConfig {
             temperature_threshold: 80.0,
             enable_alerts: true,
             log_file: "system_monitor.log".to_string(),
+            cpu_history_size: 32,
+            top_processes: 10,
+            sort_by: SortBy::Cpu,
+            network_threshold: 104_857_600.0, // 100 MB/s
+            disk_threshold: 104_857_600.0,    // 100 MB/s
+            output_mode: OutputMode::Full,
         }
     }
 }
@@ -67,6 +155,8 @@ pub struct SystemMonitorRunner {
     monitor: SystemMonitor,
     config: MonitorConfig,
     running: Arc<Mutex<bool>>,
+    cpu_history: Vec<VecDeque<f32>>,
+    last_io_sample: Option<(Instant, Vec<NetworkStats>, Vec<DiskStats>)>,
 }
 
 impl SystemMonitorRunner {
@@ -75,6 +165,8 @@ impl SystemMonitorRunner {
             monitor: SystemMonitor::new(),
             config,
             running: Arc::new(Mutex::new(false)),
+            cpu_history: Vec::new(),
+            last_io_sample: None,
         }
     }
 
@@ -96,15 +188,23 @@ impl SystemMonitorRunner {
             }
 
             iteration += 1;
-            let stats = self.monitor.get_system_stats();
-            
-            self.print_stats(&stats, iteration);
-            
+            let mut stats = self.monitor.get_system_stats(self.config.top_processes, self.config.sort_by);
+            self.update_cpu_history(&stats.cpu.per_core);
+            self.update_io_rates(&mut stats);
+
+            match self.config.output_mode {
+                OutputMode::Full => self.print_stats(&stats, iteration),
+                OutputMode::Basic => self.print_basic(&stats),
+                OutputMode::Json => self.print_json(&stats)?,
+            }
+
             if self.config.enable_alerts {
                 self.check_alerts(&stats);
             }
 
-            self.log_stats(&stats)?;
+            if self.config.output_mode != OutputMode::Json {
+                self.log_stats(&stats)?;
+            }
 
             if self.config.duration > 0 {
                 let elapsed = start_time.elapsed()?.as_secs();
@@ -124,6 +224,63 @@ impl SystemMonitorRunner {
         *running = false;
     }
 
+    fn update_cpu_history(&mut self, per_core: &[f32]) {
+        if self.cpu_history.len() != per_core.len() {
+            self.cpu_history = vec![VecDeque::with_capacity(self.config.cpu_history_size); per_core.len()];
+        }
+
+        for (history, &usage) in self.cpu_history.iter_mut().zip(per_core.iter()) {
+            if history.len() >= self.config.cpu_history_size {
+                history.pop_front();
+            }
+            history.push_back(usage);
+        }
+    }
+
+    /// Fills in the per-interface/per-mount throughput rates by diffing the
+    /// cumulative byte counters against the previous sample over the real
+    /// wall-clock time elapsed, rather than the configured interval (which drifts).
+    fn update_io_rates(&mut self, stats: &mut SystemStats) {
+        let now = Instant::now();
+
+        if let Some((prev_time, prev_network, prev_disks)) = self.last_io_sample.take() {
+            let elapsed = now.duration_since(prev_time).as_secs_f64();
+            if elapsed > 0.0 {
+                for net in stats.network.iter_mut() {
+                    if let Some(prev) = prev_network.iter().find(|p| p.interface == net.interface) {
+                        net.rx_bytes_per_sec = bytes_per_sec(prev.rx_bytes, net.rx_bytes, elapsed);
+                        net.tx_bytes_per_sec = bytes_per_sec(prev.tx_bytes, net.tx_bytes, elapsed);
+                    }
+                }
+
+                for disk in stats.disks.iter_mut() {
+                    if let Some(prev) = prev_disks.iter().find(|p| p.mount_point == disk.mount_point) {
+                        disk.read_bytes_per_sec = bytes_per_sec(prev.read_bytes, disk.read_bytes, elapsed);
+                        disk.write_bytes_per_sec = bytes_per_sec(prev.write_bytes, disk.write_bytes, elapsed);
+                    }
+                }
+            }
+        }
+
+        self.last_io_sample = Some((now, stats.network.clone(), stats.disks.clone()));
+    }
+
+    /// Renders a single condensed line for `OutputMode::Basic`.
+    fn print_basic(&self, stats: &SystemStats) {
+        let memory_percent = (stats.memory.used as f32 / stats.memory.total as f32) * 100.0;
+        let load = stats.cpu.load_avg.map(|l| l[0]).unwrap_or(0.0);
+        println!(
+            "cpu={:.1}% mem={:.1}% temp={:.1}°C load={:.2}",
+            stats.cpu.usage_percent, memory_percent, stats.cpu.temperature, load
+        );
+    }
+
+    /// Emits one JSON object for `OutputMode::Json`, for piping into other tools.
+    fn print_json(&self, stats: &SystemStats) -> Result<(), Box<dyn std::error::Error>> {
+        println!("{}", serde_json::to_string(stats)?);
+        Ok(())
+    }
+
     fn print_stats(&self, stats: &SystemStats, iteration: u64) {
         println!("=== System Monitor - Iteration {} ===", iteration);
         println!("Timestamp: {}", stats.timestamp);
@@ -135,9 +292,25 @@ impl SystemMonitorRunner {
         println!("  Usage: {:.1}%", stats.cpu.usage_percent);
         println!("  Cores: {}", stats.cpu.cores);
         println!("  Temperature: {:.1}°C", stats.cpu.temperature);
+        if !stats.cpu.sensors.is_empty() {
+            println!("  Sensors:");
+            for sensor in &stats.cpu.sensors {
+                match sensor.critical {
+                    Some(critical) => println!("    {}: {:.1}°C (crit {:.1}°C)", sensor.label, sensor.temp_c, critical),
+                    None => println!("    {}: {:.1}°C", sensor.label, sensor.temp_c),
+                }
+            }
+        }
         if let Some(load_avg) = &stats.cpu.load_avg {
             println!("  Load Average: {:.2}, {:.2}, {:.2}", load_avg[0], load_avg[1], load_avg[2]);
         }
+        if !self.cpu_history.is_empty() {
+            println!("  Per-Core History:");
+            for (i, history) in self.cpu_history.iter().enumerate() {
+                let usage = stats.cpu.per_core.get(i).copied().unwrap_or(0.0);
+                println!("    Core {:>2}: {} {:>5.1}%", i, render_sparkline(history), usage);
+            }
+        }
         println!();
         
         println!("Memory Stats:");
@@ -155,6 +328,49 @@ impl SystemMonitorRunner {
                      (stats.memory.swap_used as f32 / stats.memory.swap_total as f32) * 100.0);
         }
         
+        if !stats.network.is_empty() {
+            println!();
+            println!("Network Stats:");
+            for net in &stats.network {
+                println!(
+                    "  {}: rx {:.2} MB/s, tx {:.2} MB/s",
+                    net.interface,
+                    net.rx_bytes_per_sec / 1_000_000.0,
+                    net.tx_bytes_per_sec / 1_000_000.0
+                );
+            }
+        }
+
+        if !stats.disks.is_empty() {
+            println!();
+            println!("Disk Stats:");
+            for disk in &stats.disks {
+                println!(
+                    "  {}: {} MB free / {} MB total, read {:.2} MB/s, write {:.2} MB/s",
+                    disk.mount_point,
+                    disk.available_bytes / 1024 / 1024,
+                    disk.total_bytes / 1024 / 1024,
+                    disk.read_bytes_per_sec / 1_000_000.0,
+                    disk.write_bytes_per_sec / 1_000_000.0
+                );
+            }
+        }
+
+        if !stats.processes.is_empty() {
+            println!();
+            println!("Top Processes:");
+            println!("  {:<8} {:<20} {:>7} {:>10}", "PID", "NAME", "CPU%", "MEM(MB)");
+            for process in &stats.processes {
+                println!(
+                    "  {:<8} {:<20} {:>6.1}% {:>8} MB",
+                    process.pid,
+                    process.name,
+                    process.cpu_percent,
+                    process.memory_bytes / 1024 / 1024
+                );
+            }
+        }
+
         println!("{}", "=".repeat(50));
         println!();
     }
@@ -169,8 +385,32 @@ impl SystemMonitorRunner {
             eprintln!("⚠️  ALERT: High memory usage: {:.1}%", memory_percent);
         }
         
-        if stats.cpu.temperature > self.config.temperature_threshold {
-            eprintln!("⚠️  ALERT: High CPU temperature: {:.1}°C", stats.cpu.temperature);
+        for sensor in &stats.cpu.sensors {
+            if sensor.temp_c > self.config.temperature_threshold {
+                eprintln!("⚠️  ALERT: High temperature on {}: {:.1}°C", sensor.label, sensor.temp_c);
+            }
+        }
+
+        for net in &stats.network {
+            if net.rx_bytes_per_sec > self.config.network_threshold || net.tx_bytes_per_sec > self.config.network_threshold {
+                eprintln!(
+                    "⚠️  ALERT: High network throughput on {}: rx {:.1} MB/s, tx {:.1} MB/s",
+                    net.interface,
+                    net.rx_bytes_per_sec / 1_000_000.0,
+                    net.tx_bytes_per_sec / 1_000_000.0
+                );
+            }
+        }
+
+        for disk in &stats.disks {
+            if disk.read_bytes_per_sec > self.config.disk_threshold || disk.write_bytes_per_sec > self.config.disk_threshold {
+                eprintln!(
+                    "⚠️  ALERT: High disk throughput on {}: read {:.1} MB/s, write {:.1} MB/s",
+                    disk.mount_point,
+                    disk.read_bytes_per_sec / 1_000_000.0,
+                    disk.write_bytes_per_sec / 1_000_000.0
+                );
+            }
         }
     }
 
@@ -188,82 +428,371 @@ impl SystemMonitorRunner {
     }
 }
 
+/// Platform-specific system data collection. `SystemMonitor` picks an
+/// implementation at compile time via `make_collector`, so adding a new
+/// platform only means adding a new impl of this trait, not touching the
+/// runner or stats plumbing.
+pub trait StatsCollector {
+    fn cpu(&mut self, system: &sysinfo::System) -> (f32, Vec<f32>);
+    fn memory(&self, system: &sysinfo::System) -> MemoryStats;
+    fn temperature(&self, system: &sysinfo::System) -> Vec<TempSensor>;
+    fn uptime(&self, system: &sysinfo::System) -> u64;
+}
+
+/// Shared by every collector: sysinfo already tracks memory portably, so there's
+/// no OS-specific reader to abstract over here.
+fn collect_memory_stats(system: &sysinfo::System) -> MemoryStats {
+    let total = system.total_memory();
+    let used = system.used_memory();
+    let available = system.available_memory();
+    let swap_total = system.total_swap();
+    let swap_used = system.used_swap();
+
+    let pressure_score = (used as f32 / total as f32) * 100.0;
+
+    MemoryStats {
+        total,
+        used,
+        available,
+        pressure_score,
+        swap_total,
+        swap_used,
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux_collector {
+    use super::*;
+
+    /// Collects CPU, temperature, and uptime from `/proc` and `/sys`, as symon
+    /// has always done on Linux.
+    pub struct LinuxCollector {
+        prev_proc_stat: Option<ProcStatSample>,
+    }
+
+    impl LinuxCollector {
+        pub fn new() -> Self {
+            Self { prev_proc_stat: None }
+        }
+    }
+
+    impl StatsCollector for LinuxCollector {
+        /// Computes aggregate and per-core CPU usage from the delta between the
+        /// current `/proc/stat` reading and the previous one. Returns all zeros
+        /// on the first sample, since there is no prior reading to diff against.
+        fn cpu(&mut self, _system: &sysinfo::System) -> (f32, Vec<f32>) {
+            let sample = match read_proc_stat() {
+                Ok(sample) => sample,
+                Err(_) => return (0.0, Vec::new()),
+            };
+
+            let per_core_len = sample.per_cpu.len();
+            let prev = self.prev_proc_stat.replace(sample.clone());
+
+            let Some(prev) = prev else {
+                return (0.0, vec![0.0; per_core_len]);
+            };
+
+            let usage_percent = cpu_usage_from_delta(&prev.total, &sample.total);
+            let per_core = sample
+                .per_cpu
+                .iter()
+                .zip(prev.per_cpu.iter())
+                .map(|(curr, prev)| cpu_usage_from_delta(prev, curr))
+                .collect();
+
+            (usage_percent, per_core)
+        }
+
+        fn memory(&self, system: &sysinfo::System) -> MemoryStats {
+            collect_memory_stats(system)
+        }
+
+        /// Walks `/sys/class/hwmon/hwmon*` for `tempN_input` sensors, falling back
+        /// to `/sys/class/thermal/thermal_zone*` when no hwmon sensors are present.
+        fn temperature(&self, _system: &sysinfo::System) -> Vec<TempSensor> {
+            let mut sensors = Vec::new();
+
+            if let Ok(entries) = fs::read_dir("/sys/class/hwmon") {
+                for entry in entries.flatten() {
+                    let hwmon_path = entry.path();
+                    let chip_name = fs::read_to_string(hwmon_path.join("name"))
+                        .map(|s| s.trim().to_string())
+                        .unwrap_or_else(|_| "unknown".to_string());
+
+                    let Ok(files) = fs::read_dir(&hwmon_path) else {
+                        continue;
+                    };
+
+                    for file in files.flatten() {
+                        let file_name = file.file_name();
+                        let file_name = file_name.to_string_lossy();
+                        if !file_name.starts_with("temp") || !file_name.ends_with("_input") {
+                            continue;
+                        }
+                        let prefix = file_name.trim_end_matches("_input");
+
+                        let Ok(raw) = fs::read_to_string(hwmon_path.join(file_name.as_ref())) else {
+                            continue;
+                        };
+                        let Some(temp_c) = parse_millidegrees(&raw) else {
+                            continue;
+                        };
+
+                        let label = fs::read_to_string(hwmon_path.join(format!("{}_label", prefix)))
+                            .map(|s| s.trim().to_string())
+                            .unwrap_or_else(|_| format!("{} {}", chip_name, prefix));
+
+                        let critical = fs::read_to_string(hwmon_path.join(format!("{}_crit", prefix)))
+                            .ok()
+                            .and_then(|s| parse_millidegrees(&s));
+
+                        sensors.push(TempSensor {
+                            label,
+                            temp_c,
+                            critical,
+                        });
+                    }
+                }
+            }
+
+            if sensors.is_empty() {
+                if let Ok(entries) = fs::read_dir("/sys/class/thermal") {
+                    for entry in entries.flatten() {
+                        let zone_path = entry.path();
+                        let zone_name = zone_path.file_name().unwrap_or_default().to_string_lossy().into_owned();
+                        if !zone_name.starts_with("thermal_zone") {
+                            continue;
+                        }
+
+                        let Ok(raw) = fs::read_to_string(zone_path.join("temp")) else {
+                            continue;
+                        };
+                        let Some(temp_c) = parse_millidegrees(&raw) else {
+                            continue;
+                        };
+
+                        let label = fs::read_to_string(zone_path.join("type"))
+                            .map(|s| s.trim().to_string())
+                            .unwrap_or(zone_name);
+
+                        sensors.push(TempSensor {
+                            label,
+                            temp_c,
+                            critical: None,
+                        });
+                    }
+                }
+            }
+
+            sensors
+        }
+
+        fn uptime(&self, _system: &sysinfo::System) -> u64 {
+            read_uptime()
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod fallback_collector {
+    use super::*;
+
+    /// Sysinfo-only collector for platforms without `/proc`/`/sys`, relying on
+    /// sysinfo's own CPU, components, and uptime APIs.
+    pub struct FallbackCollector;
+
+    impl StatsCollector for FallbackCollector {
+        fn cpu(&mut self, system: &sysinfo::System) -> (f32, Vec<f32>) {
+            let per_core: Vec<f32> = system.cpus().iter().map(|cpu| cpu.cpu_usage()).collect();
+            let usage_percent = if per_core.is_empty() {
+                0.0
+            } else {
+                per_core.iter().sum::<f32>() / per_core.len() as f32
+            };
+            (usage_percent, per_core)
+        }
+
+        fn memory(&self, system: &sysinfo::System) -> MemoryStats {
+            collect_memory_stats(system)
+        }
+
+        fn temperature(&self, system: &sysinfo::System) -> Vec<TempSensor> {
+            system
+                .components()
+                .iter()
+                .map(|component| TempSensor {
+                    label: component.label().to_string(),
+                    temp_c: component.temperature(),
+                    critical: component.critical_temperature(),
+                })
+                .collect()
+        }
+
+        fn uptime(&self, _system: &sysinfo::System) -> u64 {
+            sysinfo::System::uptime()
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+use linux_collector::LinuxCollector;
+#[cfg(not(target_os = "linux"))]
+use fallback_collector::FallbackCollector;
+
+#[cfg(target_os = "linux")]
+fn make_collector() -> Box<dyn StatsCollector + Send> {
+    Box::new(LinuxCollector::new())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn make_collector() -> Box<dyn StatsCollector + Send> {
+    Box::new(FallbackCollector)
+}
+
 pub struct SystemMonitor {
     system: sysinfo::System,
+    collector: Box<dyn StatsCollector + Send>,
 }
 
 impl SystemMonitor {
     pub fn new() -> Self {
         let mut system = sysinfo::System::new_all();
         system.refresh_all();
-        
-        Self { system }
+
+        Self {
+            system,
+            collector: make_collector(),
+        }
     }
 
     pub fn refresh(&mut self) {
         self.system.refresh_all();
     }
 
-    pub fn get_system_stats(&mut self) -> SystemStats {
+    pub fn get_system_stats(&mut self, top_processes: usize, sort_by: SortBy) -> SystemStats {
         self.refresh();
-        
+
         SystemStats {
             timestamp: get_timestamp(),
             cpu: self.get_cpu_stats(),
             memory: self.get_memory_stats(),
             system_info: self.get_system_info(),
+            processes: self.get_process_stats(top_processes, sort_by),
+            network: self.get_network_stats(),
+            disks: self.get_disk_stats(),
         }
     }
 
     fn get_cpu_stats(&mut self) -> CpuStats {
-        let cpus = self.system.cpus();
-        let usage_percent = cpus.iter().map(|cpu| cpu.cpu_usage()).sum::<f32>() / cpus.len() as f32;
-        let temperature = self.get_cpu_temperature();
+        let cores = self.system.cpus().len();
+        let (usage_percent, per_core) = self.collector.cpu(&self.system);
+        let sensors = self.collector.temperature(&self.system);
+        let temperature = if sensors.is_empty() {
+            0.0
+        } else {
+            sensors.iter().map(|s| s.temp_c).sum::<f32>() / sensors.len() as f32
+        };
         let load_avg = self.get_load_average();
-        
+
         CpuStats {
             usage_percent,
-            cores: cpus.len(),
+            cores,
             temperature,
             load_avg,
+            per_core,
+            sensors,
         }
     }
 
     fn get_memory_stats(&self) -> MemoryStats {
-        let total = self.system.total_memory();
-        let used = self.system.used_memory();
-        let available = self.system.available_memory();
-        let swap_total = self.system.total_swap();
-        let swap_used = self.system.used_swap();
-        
-        let pressure_score = (used as f32 / total as f32) * 100.0;
-        
-        MemoryStats {
-            total,
-            used,
-            available,
-            pressure_score,
-            swap_total,
-            swap_used,
+        self.collector.memory(&self.system)
+    }
+
+    fn get_process_stats(&self, top_n: usize, sort_by: SortBy) -> Vec<ProcessStats> {
+        let mut processes: Vec<ProcessStats> = self
+            .system
+            .processes()
+            .iter()
+            .map(|(pid, process)| {
+                let disk_usage = process.disk_usage();
+                ProcessStats {
+                    pid: pid.as_u32(),
+                    name: process.name().to_string(),
+                    cpu_percent: process.cpu_usage(),
+                    memory_bytes: process.memory(),
+                    disk_read_bytes: disk_usage.total_read_bytes,
+                    disk_write_bytes: disk_usage.total_written_bytes,
+                }
+            })
+            .collect();
+
+        match sort_by {
+            SortBy::Cpu => processes.sort_by(|a, b| b.cpu_percent.partial_cmp(&a.cpu_percent).unwrap_or(std::cmp::Ordering::Equal)),
+            SortBy::Memory => processes.sort_by_key(|p| std::cmp::Reverse(p.memory_bytes)),
         }
+
+        processes.truncate(top_n);
+        processes
+    }
+
+    /// Reads cumulative per-interface byte counters. Rates are left at `0.0` here;
+    /// `SystemMonitorRunner` fills them in once it has a previous sample to diff against.
+    ///
+    /// `Networks` is its own standalone, freshly-listed snapshot (sysinfo moved it off
+    /// `System` in 0.30), so there's nothing on `self.system` to refresh first.
+    fn get_network_stats(&self) -> Vec<NetworkStats> {
+        let networks = sysinfo::Networks::new_with_refreshed_list();
+
+        networks
+            .iter()
+            .map(|(interface, data)| NetworkStats {
+                interface: interface.clone(),
+                rx_bytes: data.total_received(),
+                tx_bytes: data.total_transmitted(),
+                rx_bytes_per_sec: 0.0,
+                tx_bytes_per_sec: 0.0,
+            })
+            .collect()
+    }
+
+    /// Pairs sysinfo's per-mount total/available space with cumulative
+    /// read/write byte counters from `/proc/diskstats`. Rates are left at `0.0`
+    /// here; `SystemMonitorRunner` fills them in once it has a previous sample.
+    ///
+    /// `Disks` is likewise its own standalone, freshly-listed snapshot as of sysinfo 0.30.
+    fn get_disk_stats(&self) -> Vec<DiskStats> {
+        let diskstats = read_proc_diskstats().unwrap_or_default();
+        let disks = sysinfo::Disks::new_with_refreshed_list();
+
+        disks
+            .iter()
+            .map(|disk| {
+                let mount_point = disk.mount_point().to_string_lossy().into_owned();
+                let device_name = disk.name().to_string_lossy();
+                let device_name = device_name.trim_start_matches("/dev/");
+                let (read_bytes, write_bytes) = diskstats.get(device_name).copied().unwrap_or((0, 0));
+
+                DiskStats {
+                    mount_point,
+                    total_bytes: disk.total_space(),
+                    available_bytes: disk.available_space(),
+                    read_bytes,
+                    write_bytes,
+                    read_bytes_per_sec: 0.0,
+                    write_bytes_per_sec: 0.0,
+                }
+            })
+            .collect()
     }
 
     fn get_system_info(&self) -> BasicSystemInfo {
         BasicSystemInfo {
             hostname: sysinfo::System::host_name().unwrap_or_default(),
-            uptime: read_uptime(),
+            uptime: self.collector.uptime(&self.system),
         }
     }
 
-    fn get_cpu_temperature(&self) -> f32 {
-        if let Ok(temp_str) = fs::read_to_string("/sys/class/thermal/thermal_zone0/temp") {
-            if let Ok(temp_millic) = temp_str.trim().parse::<i32>() {
-                return temp_millic as f32 / 1000.0;
-            }
-        }
-        0.0
-    }
-
     fn get_load_average(&self) -> Option<[f32; 3]> {
         if let Ok(loadavg_str) = fs::read_to_string("/proc/loadavg") {
             let parts: Vec<&str> = loadavg_str.split_whitespace().collect();
@@ -297,14 +826,23 @@ fn read_uptime() -> u64 {
     0
 }
 
+/// Process-wide `SystemMonitor` shared by the standalone `get_cpu_usage`/
+/// `get_cpu_temperature` helpers, so repeated calls accumulate delta state
+/// (e.g. the previous `/proc/stat` sample) instead of each call starting cold.
+fn shared_monitor() -> &'static Mutex<SystemMonitor> {
+    static MONITOR: OnceLock<Mutex<SystemMonitor>> = OnceLock::new();
+    MONITOR.get_or_init(|| Mutex::new(SystemMonitor::new()))
+}
+
+/// Current aggregate CPU usage, computed the same way as `SystemMonitor::get_cpu_stats`
+/// (stateful `/proc/stat` deltas on Linux, sysinfo on other platforms), via a shared
+/// monitor instance rather than a blocking sleep. On Linux, returns 0.0 the first time
+/// the shared monitor is sampled (by this function or `get_cpu_temperature`), since
+/// there is no prior reading to diff against yet; call periodically for a live value.
 pub fn get_cpu_usage() -> f32 {
-    let mut system = sysinfo::System::new_all();
-    system.refresh_cpu();
-    std::thread::sleep(std::time::Duration::from_millis(200));
-    system.refresh_cpu();
-    
-    let cpus = system.cpus();
-    cpus.iter().map(|cpu| cpu.cpu_usage()).sum::<f32>() / cpus.len() as f32
+    let mut monitor = shared_monitor().lock().unwrap();
+    monitor.refresh();
+    monitor.get_cpu_stats().usage_percent
 }
 
 pub fn get_memory_usage() -> (u64, u64, f32) {
@@ -322,36 +860,105 @@ pub fn get_system_uptime() -> u64 {
     read_uptime()
 }
 
+/// Average temperature across every sensor found by the same hwmon/thermal-zone
+/// discovery pass `SystemMonitor::get_cpu_stats` uses, via the shared monitor
+/// instance. Returns 0.0 when no sensors are found.
 pub fn get_cpu_temperature() -> f32 {
-    if let Ok(temp_str) = fs::read_to_string("/sys/class/thermal/thermal_zone0/temp") {
-        if let Ok(temp_millic) = temp_str.trim().parse::<i32>() {
-            return temp_millic as f32 / 1000.0;
+    let mut monitor = shared_monitor().lock().unwrap();
+    monitor.refresh();
+    monitor.get_cpu_stats().temperature
+}
+
+/// Jiffy counters for a single CPU line (`cpu` or `cpuN`) in `/proc/stat`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CpuJiffies {
+    pub user: u64,
+    pub nice: u64,
+    pub system: u64,
+    pub idle: u64,
+    pub iowait: u64,
+}
+
+impl CpuJiffies {
+    fn from_fields(values: &[&str]) -> Self {
+        Self {
+            user: values.first().and_then(|v| v.parse().ok()).unwrap_or(0),
+            nice: values.get(1).and_then(|v| v.parse().ok()).unwrap_or(0),
+            system: values.get(2).and_then(|v| v.parse().ok()).unwrap_or(0),
+            idle: values.get(3).and_then(|v| v.parse().ok()).unwrap_or(0),
+            iowait: values.get(4).and_then(|v| v.parse().ok()).unwrap_or(0),
         }
     }
-    0.0
+
+    /// Sum of all tracked jiffy counters.
+    pub fn total(&self) -> u64 {
+        self.user + self.nice + self.system + self.idle + self.iowait
+    }
+
+    /// Jiffies where the CPU was not doing work, including I/O wait.
+    pub fn idle_total(&self) -> u64 {
+        self.idle + self.iowait
+    }
+}
+
+/// A full `/proc/stat` CPU reading: the aggregate `cpu` line plus each `cpuN` line,
+/// in core-index order.
+#[derive(Debug, Clone, Default)]
+pub struct ProcStatSample {
+    pub total: CpuJiffies,
+    pub per_cpu: Vec<CpuJiffies>,
+}
+
+/// Computes a throughput rate from two cumulative byte counters and the real
+/// wall-clock time elapsed between the samples that produced them.
+fn bytes_per_sec(prev: u64, curr: u64, elapsed_secs: f64) -> f64 {
+    curr.saturating_sub(prev) as f64 / elapsed_secs
+}
+
+/// Parses a raw `/sys` temperature reading in millidegrees Celsius into °C.
+/// Used for both hwmon `tempN_input`/`tempN_crit` files and thermal zone `temp` files.
+fn parse_millidegrees(raw: &str) -> Option<f32> {
+    raw.trim().parse::<i32>().ok().map(|v| v as f32 / 1000.0)
 }
 
-pub fn read_proc_stat() -> Result<HashMap<String, u64>, std::io::Error> {
+/// Computes the CPU usage percentage over the window between two jiffy readings.
+fn cpu_usage_from_delta(prev: &CpuJiffies, curr: &CpuJiffies) -> f32 {
+    let total_delta = curr.total().saturating_sub(prev.total());
+    if total_delta == 0 {
+        return 0.0;
+    }
+
+    let idle_delta = curr.idle_total().saturating_sub(prev.idle_total());
+    ((total_delta.saturating_sub(idle_delta)) as f32 / total_delta as f32) * 100.0
+}
+
+pub fn read_proc_stat() -> Result<ProcStatSample, std::io::Error> {
     let content = fs::read_to_string("/proc/stat")?;
-    let mut stats = HashMap::new();
-    
+    let mut sample = ProcStatSample::default();
+
     for line in content.lines() {
-        if line.starts_with("cpu ") {
-            let values: Vec<&str> = line.split_whitespace().collect();
-            if values.len() >= 5 {
-                stats.insert("user".to_string(), values[1].parse().unwrap_or(0));
-                stats.insert("nice".to_string(), values[2].parse().unwrap_or(0));
-                stats.insert("system".to_string(), values[3].parse().unwrap_or(0));
-                stats.insert("idle".to_string(), values[4].parse().unwrap_or(0));
-                if values.len() > 5 {
-                    stats.insert("iowait".to_string(), values[5].parse().unwrap_or(0));
-                }
+        if let Some(rest) = line.strip_prefix("cpu ") {
+            let values: Vec<&str> = rest.split_whitespace().collect();
+            sample.total = CpuJiffies::from_fields(&values);
+        } else if let Some(rest) = line.strip_prefix("cpu") {
+            let Some((index_str, rest)) = rest.split_once(' ') else {
+                continue;
+            };
+            let Ok(index) = index_str.parse::<usize>() else {
+                continue;
+            };
+
+            let values: Vec<&str> = rest.split_whitespace().collect();
+            let jiffies = CpuJiffies::from_fields(&values);
+
+            if index >= sample.per_cpu.len() {
+                sample.per_cpu.resize(index + 1, CpuJiffies::default());
             }
-            break;
+            sample.per_cpu[index] = jiffies;
         }
     }
-    
-    Ok(stats)
+
+    Ok(sample)
 }
 
 pub fn read_proc_meminfo() -> Result<HashMap<String, u64>, std::io::Error> {
@@ -374,6 +981,28 @@ pub fn read_proc_meminfo() -> Result<HashMap<String, u64>, std::io::Error> {
     Ok(meminfo)
 }
 
+/// Reads cumulative read/write bytes per block device (including partitions)
+/// from `/proc/diskstats`, keyed by device name (e.g. `"sda1"`, `"nvme0n1"`).
+pub fn read_proc_diskstats() -> Result<HashMap<String, (u64, u64)>, std::io::Error> {
+    let content = fs::read_to_string("/proc/diskstats")?;
+    let mut stats = HashMap::new();
+
+    for line in content.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 10 {
+            continue;
+        }
+
+        let device = fields[2].to_string();
+        let sectors_read: u64 = fields[5].parse().unwrap_or(0);
+        let sectors_written: u64 = fields[9].parse().unwrap_or(0);
+
+        stats.insert(device, (sectors_read * 512, sectors_written * 512));
+    }
+
+    Ok(stats)
+}
+
 #[no_mangle]
 pub extern "C" fn run_system_monitor(
     interval: u64,
@@ -402,6 +1031,7 @@ pub extern "C" fn run_system_monitor(
         temperature_threshold,
         enable_alerts,
         log_file: log_file_str,
+        ..MonitorConfig::default()
     };
 
     let mut runner = SystemMonitorRunner::new(config);
@@ -449,8 +1079,9 @@ pub extern "C" fn run_system_monitor_with_config(config_json: *const std::os::ra
 #[no_mangle]
 pub extern "C" fn get_system_stats_json() -> *mut std::os::raw::c_char {
     let mut monitor = SystemMonitor::new();
-    let stats = monitor.get_system_stats();
-    
+    let config = MonitorConfig::default();
+    let stats = monitor.get_system_stats(config.top_processes, config.sort_by);
+
     match serde_json::to_string(&stats) {
         Ok(json_str) => {
             let c_str = std::ffi::CString::new(json_str).unwrap();
@@ -482,6 +1113,53 @@ pub extern "C" fn get_cpu_temperature_c() -> f32 {
     get_cpu_temperature()
 }
 
+/// Signals a process by pid, trying `SIGTERM` first and escalating to `SIGKILL`
+/// if the process is still alive shortly afterwards. Returns `0` on success
+/// (the process is confirmed gone), `1` if it could not be found or killed.
+///
+/// `kill_with` only reports whether the signal was accepted by the kernel, not
+/// whether the process actually exited, so each signal is followed by a brief
+/// wait and a `refresh_process` liveness check before deciding whether to escalate.
+#[no_mangle]
+pub extern "C" fn kill_process(pid: u32) -> i32 {
+    let mut system = sysinfo::System::new_all();
+    system.refresh_all();
+
+    let sys_pid = sysinfo::Pid::from_u32(pid);
+
+    let sent_term = match system.process(sys_pid) {
+        Some(process) => process.kill_with(sysinfo::Signal::Term).unwrap_or(false),
+        None => return 1,
+    };
+
+    if !sent_term {
+        return 1;
+    }
+
+    thread::sleep(Duration::from_millis(200));
+
+    if !system.refresh_process(sys_pid) || system.process(sys_pid).is_none() {
+        return 0;
+    }
+
+    let sent_kill = match system.process(sys_pid) {
+        Some(process) => process.kill_with(sysinfo::Signal::Kill).unwrap_or(false),
+        None => return 0,
+    };
+
+    if !sent_kill {
+        return 1;
+    }
+
+    thread::sleep(Duration::from_millis(200));
+
+    if !system.refresh_process(sys_pid) || system.process(sys_pid).is_none() {
+        0
+    } else {
+        1
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn free_string(ptr: *mut std::os::raw::c_char) {
     if !ptr.is_null() {
@@ -504,8 +1182,8 @@ mod tests {
     #[test]
     fn test_get_system_stats() {
         let mut monitor = SystemMonitor::new();
-        let stats = monitor.get_system_stats();
-        
+        let stats = monitor.get_system_stats(10, SortBy::Cpu);
+
         assert!(stats.timestamp > 0);
         assert!(stats.cpu.cores > 0);
         assert!(stats.memory.total > 0);
@@ -528,13 +1206,77 @@ mod tests {
         assert!(temp >= 0.0);
     }
 
+    #[test]
+    fn test_sparkline_char_boundaries() {
+        assert_eq!(sparkline_char(0.0), ' ');
+        assert_eq!(sparkline_char(50.0), '▄');
+        assert_eq!(sparkline_char(100.0), '█');
+        assert_eq!(sparkline_char(150.0), '█'); // clamps above 100
+    }
+
+    #[test]
+    fn test_render_sparkline() {
+        let history: VecDeque<f32> = VecDeque::from([0.0, 50.0, 100.0]);
+        assert_eq!(render_sparkline(&history), " ▄█");
+    }
+
+    #[test]
+    fn test_cpu_usage_from_delta() {
+        // 50 jiffies of work out of 100 total -> 50% usage.
+        let prev = CpuJiffies { user: 100, nice: 0, system: 0, idle: 100, iowait: 0 };
+        let curr = CpuJiffies { user: 150, nice: 0, system: 0, idle: 150, iowait: 0 };
+        assert_eq!(cpu_usage_from_delta(&prev, &curr), 50.0);
+
+        // All idle -> 0% usage.
+        let all_idle_prev = CpuJiffies { user: 0, nice: 0, system: 0, idle: 100, iowait: 0 };
+        let all_idle_curr = CpuJiffies { user: 0, nice: 0, system: 0, idle: 200, iowait: 0 };
+        assert_eq!(cpu_usage_from_delta(&all_idle_prev, &all_idle_curr), 0.0);
+
+        // All work, no idle -> 100% usage.
+        let busy_prev = CpuJiffies { user: 0, nice: 0, system: 0, idle: 0, iowait: 0 };
+        let busy_curr = CpuJiffies { user: 100, nice: 0, system: 0, idle: 0, iowait: 0 };
+        assert_eq!(cpu_usage_from_delta(&busy_prev, &busy_curr), 100.0);
+
+        // No elapsed jiffies (first sample or unchanged reading) -> 0%, no divide-by-zero.
+        let same = CpuJiffies { user: 10, nice: 0, system: 0, idle: 10, iowait: 0 };
+        assert_eq!(cpu_usage_from_delta(&same, &same), 0.0);
+
+        // iowait counts as idle.
+        let iowait_prev = CpuJiffies { user: 0, nice: 0, system: 0, idle: 0, iowait: 0 };
+        let iowait_curr = CpuJiffies { user: 25, nice: 0, system: 0, idle: 0, iowait: 75 };
+        assert_eq!(cpu_usage_from_delta(&iowait_prev, &iowait_curr), 25.0);
+    }
+
+    #[test]
+    fn test_bytes_per_sec() {
+        // 1,000,000 bytes over 1 second -> 1,000,000 bytes/sec.
+        assert_eq!(bytes_per_sec(0, 1_000_000, 1.0), 1_000_000.0);
+
+        // Same delta over half the time -> double the rate.
+        assert_eq!(bytes_per_sec(0, 1_000_000, 0.5), 2_000_000.0);
+
+        // No bytes transferred -> zero rate.
+        assert_eq!(bytes_per_sec(500, 500, 1.0), 0.0);
+
+        // Counter reset/wrap (curr < prev) saturates to zero instead of going negative.
+        assert_eq!(bytes_per_sec(1_000, 10, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_parse_millidegrees() {
+        assert_eq!(parse_millidegrees("45000"), Some(45.0));
+        assert_eq!(parse_millidegrees("45000\n"), Some(45.0));
+        assert_eq!(parse_millidegrees("  45000  "), Some(45.0));
+        assert_eq!(parse_millidegrees("-5000"), Some(-5.0));
+        assert_eq!(parse_millidegrees("not a number"), None);
+        assert_eq!(parse_millidegrees(""), None);
+    }
+
     #[test]
     fn test_proc_readers() {
         match read_proc_stat() {
-            Ok(stats) => {
-                assert!(stats.contains_key("user"));
-                assert!(stats.contains_key("system"));
-                assert!(stats.contains_key("idle"));
+            Ok(sample) => {
+                assert!(sample.total.total() > 0);
             }
             Err(_) => {}
         }
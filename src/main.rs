@@ -1,4 +1,4 @@
-use symon_core::{run_system_monitor_with_config, MonitorConfig};
+use symon_core::{run_system_monitor_with_config, MonitorConfig, OutputMode};
 use std::env;
 use std::fs;
 
@@ -26,10 +26,19 @@ fn main() {
         MonitorConfig::default()
     };
 
-    println!("Starting System Monitor...");
-    println!("Config: {:?}", config);
-    println!("Press Ctrl+C to stop");
-    println!();
+    // OutputMode::Json streams NDJSON on stdout for downstream parsers, so this
+    // banner goes to stderr where it won't corrupt the first lines of that stream.
+    if config.output_mode == OutputMode::Json {
+        eprintln!("Starting System Monitor...");
+        eprintln!("Config: {:?}", config);
+        eprintln!("Press Ctrl+C to stop");
+        eprintln!();
+    } else {
+        println!("Starting System Monitor...");
+        println!("Config: {:?}", config);
+        println!("Press Ctrl+C to stop");
+        println!();
+    }
 
     let config_json = serde_json::to_string(&config).unwrap();
     let config_cstr = std::ffi::CString::new(config_json).unwrap();